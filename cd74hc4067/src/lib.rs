@@ -10,6 +10,7 @@
 #![deny(missing_docs)]
 #![cfg_attr(not(test), no_std)]
 
+use core::convert::Infallible;
 use core::marker::PhantomData;
 
 /// Errors of this crate
@@ -35,6 +36,12 @@ use embedded_hal_0_2::digital::v2::OutputPin;
 #[cfg(not(feature = "eh0"))]
 use embedded_hal::digital::OutputPin;
 
+/// Worst-case channel switching/settling time of the CD74HC4067, in
+/// nanoseconds, per the datasheet. Used as the default for
+/// [`Cd74hc4067::settling_ns`] when the `async` feature is enabled.
+#[cfg(feature = "async")]
+pub const DEFAULT_SETTLING_NS: u32 = 25;
+
 type Resources<P, E> = (P, P, P, P, E);
 
 type CreationResult<P, E> = Result<Cd74hc4067<P, E, DisabledState>, (Error<P, E>, Resources<P, E>)>;
@@ -53,6 +60,10 @@ pub struct Cd74hc4067<P, E, State> {
     pin_2: P,
     pin_3: P,
     pin_enable: E,
+    /// Settling time awaited after a select-pin change by the `async` API,
+    /// see [`Cd74hc4067::set_channel_active_async`].
+    #[cfg(feature = "async")]
+    settling_ns: u32,
     state: PhantomData<State>,
 }
 
@@ -94,10 +105,20 @@ where
             pin_2,
             pin_3,
             pin_enable,
+            #[cfg(feature = "async")]
+            settling_ns: DEFAULT_SETTLING_NS,
             state: PhantomData::<DisabledState>,
         })
     }
 
+    /// Set the settling time awaited by the `async` API after a select-pin
+    /// change, overriding [`DEFAULT_SETTLING_NS`].
+    #[cfg(feature = "async")]
+    pub fn with_settling_ns(mut self, settling_ns: u32) -> Self {
+        self.settling_ns = settling_ns;
+        self
+    }
+
     /// Release the 5 GPIOs previously occupied
     pub fn release(self) -> Resources<P, E> {
         (
@@ -122,6 +143,8 @@ where
             pin_2: self.pin_2,
             pin_3: self.pin_3,
             pin_enable: self.pin_enable,
+            #[cfg(feature = "async")]
+            settling_ns: self.settling_ns,
             state: PhantomData::<EnabledState>,
         })
     }
@@ -179,11 +202,497 @@ where
             pin_2: self.pin_2,
             pin_3: self.pin_3,
             pin_enable: self.pin_enable,
+            #[cfg(feature = "async")]
+            settling_ns: self.settling_ns,
             state: PhantomData::<DisabledState>,
         })
     }
 }
 
+impl<P, E> Cd74hc4067<P, E, EnabledState>
+where
+    P: OutputPin,
+    E: OutputPin,
+{
+    /// Select channel `n` while the mux stays enabled. `n` must be between 0 and 15 inclusive.
+    ///
+    /// If an [`Error::SelectPinError`] occurs, the select pins are left in a possibly unwanted state.
+    ///
+    /// # Panics
+    ///
+    /// If `n` is out of range, then this function will panic.
+    pub fn set_channel_active(&mut self, n: u8) -> Result<(), Error<P, E>> {
+        assert!(n < 16);
+        let mut pins = [&mut self.pin_0, &mut self.pin_1, &mut self.pin_2, &mut self.pin_3];
+        for (bit, pin) in pins.iter_mut().enumerate() {
+            if n & (1 << bit) != 0 {
+                pin.set_high().map_err(Error::SelectPinError)?;
+            } else {
+                pin.set_low().map_err(Error::SelectPinError)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Infallible ergonomics for HALs whose `OutputPin::Error` is
+/// [`Infallible`] (e.g. most embassy GPIO drivers), so callers don't have
+/// to unwrap a [`Result`] that can never be `Err`.
+impl<P, E> Cd74hc4067<P, E, DisabledState>
+where
+    P: OutputPin<Error = Infallible>,
+    E: OutputPin<Error = Infallible>,
+{
+    /// Infallible equivalent of [`Self::enable`].
+    pub fn enable_infallible(self) -> Cd74hc4067<P, E, EnabledState> {
+        let Ok(enabled) = self.enable() else {
+            unreachable!("OutputPin::Error is Infallible")
+        };
+        enabled
+    }
+
+    /// Infallible equivalent of [`Self::set_channel_active`].
+    ///
+    /// # Panics
+    ///
+    /// If `n` is out of range, then this function will panic.
+    pub fn set_channel_active_infallible(&mut self, n: u8) {
+        let Ok(()) = self.set_channel_active(n) else {
+            unreachable!("OutputPin::Error is Infallible")
+        };
+    }
+}
+
+impl<P, E> Cd74hc4067<P, E, EnabledState>
+where
+    P: OutputPin<Error = Infallible>,
+    E: OutputPin<Error = Infallible>,
+{
+    /// Infallible equivalent of [`Self::disable`].
+    pub fn disable_infallible(self) -> Cd74hc4067<P, E, DisabledState> {
+        let Ok(disabled) = self.disable() else {
+            unreachable!("OutputPin::Error is Infallible")
+        };
+        disabled
+    }
+}
+
+/// Async API, behind the `async` feature.
+///
+/// Mirrors [`Cd74hc4067::set_channel_active`], [`Cd74hc4067::enable`] and
+/// [`Cd74hc4067::disable`], but awaits [`Cd74hc4067::settling_ns`] after the
+/// select pins change so the common `SIG` line is guaranteed valid before a
+/// caller samples it, instead of racing the switch.
+#[cfg(feature = "async")]
+impl<P, E> Cd74hc4067<P, E, DisabledState>
+where
+    P: OutputPin,
+    E: OutputPin,
+{
+    /// Async equivalent of [`Self::set_channel_active`]: select channel `n`,
+    /// then await [`Self::settling_ns`] before returning.
+    ///
+    /// # Panics
+    ///
+    /// If `n` is out of range, then this function will panic.
+    pub async fn set_channel_active_async(
+        &mut self,
+        n: u8,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+    ) -> Result<(), Error<P, E>> {
+        self.set_channel_active(n)?;
+        delay.delay_ns(self.settling_ns).await;
+        Ok(())
+    }
+
+    /// Async equivalent of [`Self::enable`], delegating the pin toggle to
+    /// it and then awaiting [`Self::settling_ns`] before returning, since a
+    /// caller may sample the common `SIG` line as soon as this resolves.
+    pub async fn enable_async(
+        self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+    ) -> EnableResult<P, E> {
+        let settling_ns = self.settling_ns;
+        let enabled = self.enable()?;
+        delay.delay_ns(settling_ns).await;
+        Ok(enabled)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<P, E> Cd74hc4067<P, E, EnabledState>
+where
+    P: OutputPin,
+    E: OutputPin,
+{
+    /// Async equivalent of [`Self::disable`], delegating the pin toggle to
+    /// it. Unlike [`Self::enable_async`], this does not await
+    /// [`Self::settling_ns`]: disabling drives the mux output
+    /// high-impedance immediately, and there is nothing left downstream for
+    /// a caller to sample afterwards, so there is no race to guard against.
+    pub async fn disable_async(self) -> DisableResult<P, E> {
+        self.disable()
+    }
+}
+
+#[cfg(feature = "eh0")]
+use embedded_hal_0_2::adc::OneShot;
+
+#[cfg(feature = "eh0")]
+use embedded_hal_0_2::blocking::delay::DelayUs;
+
+/// Worst-case channel switching/settling time of the CD74HC4067 per the
+/// datasheet, rounded up to whole microseconds. Used as the default settling time for
+/// this crate's blocking channel-scanning helpers
+/// ([`ScanningMux::settling_us`] and [`DigitalScanner::settling_us`]).
+pub const DEFAULT_SETTLING_US: u32 = 1;
+
+/// Error produced by [`ScanningMux`]
+#[cfg(feature = "eh0")]
+#[derive(Debug)]
+pub enum ScanError<P: OutputPin, E: OutputPin, AdcError> {
+    /// Error selecting a channel
+    Select(Error<P, E>),
+    /// Error performing the ADC conversion
+    Adc(AdcError),
+}
+
+/// Wraps an enabled [`Cd74hc4067`] together with the ADC sampling its
+/// common `SIG` line and a blocking delay, so a caller doesn't have to
+/// hand-roll the select-then-wait-then-convert loop themselves.
+#[cfg(feature = "eh0")]
+pub struct ScanningMux<P, E, Adc, Ch, Delay> {
+    mux: Cd74hc4067<P, E, EnabledState>,
+    adc: Adc,
+    channel: Ch,
+    delay: Delay,
+    settling_us: u32,
+}
+
+#[cfg(feature = "eh0")]
+impl<P, E, Adc, Ch, Delay, Word> ScanningMux<P, E, Adc, Ch, Delay>
+where
+    P: OutputPin,
+    E: OutputPin,
+    Adc: OneShot<Adc, Word, Ch>,
+    Delay: DelayUs<u32>,
+{
+    /// Wrap an already-enabled mux together with the ADC (and its channel)
+    /// connected to the common `SIG` line, and the delay used to wait out
+    /// [`Self::settling_us`] between selecting a channel and sampling it.
+    pub fn new(mux: Cd74hc4067<P, E, EnabledState>, adc: Adc, channel: Ch, delay: Delay) -> Self {
+        Self {
+            mux,
+            adc,
+            channel,
+            delay,
+            settling_us: DEFAULT_SETTLING_US,
+        }
+    }
+
+    /// Set the settling time awaited between selecting a channel and
+    /// sampling it, overriding [`DEFAULT_SETTLING_US`].
+    pub fn with_settling_us(mut self, settling_us: u32) -> Self {
+        self.settling_us = settling_us;
+        self
+    }
+
+    /// Release the wrapped mux, ADC, ADC channel and delay.
+    pub fn release(self) -> (Cd74hc4067<P, E, EnabledState>, Adc, Ch, Delay) {
+        (self.mux, self.adc, self.channel, self.delay)
+    }
+
+    /// Select channel `n`, wait out [`Self::settling_us`], then trigger a
+    /// conversion on it and return the result.
+    ///
+    /// # Panics
+    ///
+    /// If `n` is out of range, then this function will panic.
+    pub fn read_channel(&mut self, n: u8) -> Result<Word, ScanError<P, E, Adc::Error>> {
+        self.mux.set_channel_active(n).map_err(ScanError::Select)?;
+        self.delay.delay_us(self.settling_us);
+        nb::block!(self.adc.read(&mut self.channel)).map_err(ScanError::Adc)
+    }
+
+    /// Walk channels 0 through 15, filling `out` with one conversion result
+    /// per channel, waiting out [`Self::settling_us`] before each one.
+    pub fn scan_all(&mut self, out: &mut [Word; 16]) -> Result<(), ScanError<P, E, Adc::Error>> {
+        for n in 0..=15u8 {
+            out[n as usize] = self.read_channel(n)?;
+        }
+        Ok(())
+    }
+}
+
+/// A leaf mux inside a [`Cascade`]: [`Leaf::Enabled`] only while it is the
+/// currently addressed leaf, [`Leaf::Disabled`] otherwise. Transitions
+/// always go through [`Cd74hc4067::enable`]/[`Cd74hc4067::disable`], so
+/// this variant is always the truth about the leaf's physical `pin_enable`
+/// state — never out of sync with it the way a fixed `DisabledState` typing
+/// would be for a leaf that is actually the active one.
+enum Leaf<P, E> {
+    Disabled(Cd74hc4067<P, E, DisabledState>),
+    Enabled(Cd74hc4067<P, E, EnabledState>),
+}
+
+impl<P, E> Leaf<P, E>
+where
+    P: OutputPin,
+    E: OutputPin,
+{
+    /// Make this the active leaf on channel `channel`, enabling it first if
+    /// it wasn't already.
+    ///
+    /// On error, returns the leaf in whichever state the failed step left
+    /// it in (still disabled if enabling failed, enabled if selecting the
+    /// channel failed) alongside the error, mirroring
+    /// [`Cd74hc4067::enable`] -- callers get their leaf and its owned pins
+    /// back rather than losing them.
+    fn activate(self, channel: u8) -> Result<Self, (Error<P, E>, Self)> {
+        let mut mux = match self {
+            Leaf::Enabled(mux) => mux,
+            Leaf::Disabled(mux) => match mux.enable() {
+                Ok(mux) => mux,
+                Err((e, mux)) => return Err((e, Leaf::Disabled(mux))),
+            },
+        };
+        if let Err(e) = mux.set_channel_active(channel) {
+            return Err((e, Leaf::Enabled(mux)));
+        }
+        Ok(Leaf::Enabled(mux))
+    }
+
+    /// Ensure this leaf is disabled, doing nothing if it already is.
+    ///
+    /// On error, returns the leaf unchanged alongside the error, for the
+    /// same reason as [`Self::activate`].
+    fn deactivate(self) -> Result<Self, (Error<P, E>, Self)> {
+        match self {
+            Leaf::Disabled(mux) => Ok(Leaf::Disabled(mux)),
+            Leaf::Enabled(mux) => match mux.disable() {
+                Ok(mux) => Ok(Leaf::Disabled(mux)),
+                Err((e, mux)) => Err((e, Leaf::Enabled(mux))),
+            },
+        }
+    }
+}
+
+/// Chains `N` "leaf" [`Cd74hc4067`] muxes through a single root mux, giving
+/// a flat address space of up to `16 * N` channels.
+///
+/// The leaves' common `SIG` pins must be wired to the root's 16 channel
+/// inputs. [`Cascade::set_channel_active`] decodes a global channel index
+/// into `(leaf, channel)`, selects `channel` on that leaf and enables only
+/// that one leaf, disabling every other leaf so no other analog path can
+/// leak onto the shared root input.
+///
+/// This covers a two-level tree (root + `N` leaves); deeper trees can be
+/// built by nesting, e.g. by wiring a `Cascade`'s leaves to the roots of
+/// further cascades, at the cost of composing the index decoding by hand.
+pub struct Cascade<P, E, const N: usize> {
+    root: Cd74hc4067<P, E, EnabledState>,
+    leaves: [Option<Leaf<P, E>>; N],
+}
+
+impl<P, E, const N: usize> Cascade<P, E, N>
+where
+    P: OutputPin,
+    E: OutputPin,
+{
+    const FAN_OUT_FITS_ROOT: () = assert!(N <= 16, "a root Cd74hc4067 can only address 16 leaves");
+
+    /// Build a cascade from an already-enabled root mux and `N` disabled
+    /// leaf muxes, in leaf-select order (leaf 0 wired to the root's channel
+    /// 0, leaf 1 to channel 1, and so on).
+    pub fn new(
+        root: Cd74hc4067<P, E, EnabledState>,
+        leaves: [Cd74hc4067<P, E, DisabledState>; N],
+    ) -> Self {
+        let () = Self::FAN_OUT_FITS_ROOT;
+        Self {
+            root,
+            leaves: leaves.map(|leaf| Some(Leaf::Disabled(leaf))),
+        }
+    }
+
+    /// Release the root mux and the leaves, disabling whichever leaf was
+    /// active so the returned leaves are honestly typed `DisabledState` —
+    /// matching their physical `pin_enable` state rather than merely
+    /// asserting it.
+    #[allow(clippy::type_complexity)]
+    pub fn release(
+        mut self,
+    ) -> Result<
+        (
+            Cd74hc4067<P, E, EnabledState>,
+            [Cd74hc4067<P, E, DisabledState>; N],
+        ),
+        Error<P, E>,
+    > {
+        let mut disabled: [Option<Cd74hc4067<P, E, DisabledState>>; N] =
+            core::array::from_fn(|_| None);
+
+        for (out, slot) in disabled.iter_mut().zip(self.leaves.iter_mut()) {
+            let leaf = slot.take().expect("cascade leaf slot is never left empty");
+            let leaf = match leaf.deactivate() {
+                Ok(leaf) => leaf,
+                Err((e, leaf)) => {
+                    *slot = Some(leaf);
+                    return Err(e);
+                }
+            };
+            *out = match leaf {
+                Leaf::Disabled(mux) => Some(mux),
+                Leaf::Enabled(_) => unreachable!("deactivate always returns Disabled"),
+            };
+        }
+
+        Ok((
+            self.root,
+            disabled.map(|mux| mux.expect("every leaf was disabled above")),
+        ))
+    }
+
+    /// Enable global channel `index`, disabling every other leaf so only
+    /// one analog path reaches the root's common `SIG` line.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of the `0..16 * N` range, then this function will panic.
+    pub fn set_channel_active(&mut self, index: u16) -> Result<(), Error<P, E>> {
+        let total = 16 * N as u16;
+        assert!(index < total, "channel index out of range for this cascade");
+
+        let target = (index / 16) as usize;
+        let channel = (index % 16) as u8;
+
+        self.root.set_channel_active(target as u8)?;
+
+        for (i, slot) in self.leaves.iter_mut().enumerate() {
+            let leaf = slot.take().expect("cascade leaf slot is never left empty");
+            let result = if i == target {
+                leaf.activate(channel)
+            } else {
+                leaf.deactivate()
+            };
+            let leaf = match result {
+                Ok(leaf) => leaf,
+                Err((e, leaf)) => {
+                    *slot = Some(leaf);
+                    return Err(e);
+                }
+            };
+            *slot = Some(leaf);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "eh0")]
+use embedded_hal_0_2::digital::v2::InputPin;
+
+#[cfg(not(feature = "eh0"))]
+use embedded_hal::digital::InputPin;
+
+#[cfg(not(feature = "eh0"))]
+use embedded_hal::delay::DelayNs;
+
+/// Adapts [`DigitalScanner`]'s blocking delay to whichever HAL version is
+/// active ([`ScanningMux`] exists only with `eh0` and takes `DelayUs<u32>`
+/// directly): `embedded-hal`
+/// 0.2's `DelayUs<u32>` and 1.0's `DelayNs` both expose a `delay_us`
+/// method, just via differently-shaped traits (one generic over the word
+/// size, one not), so this gives both a single name to reach it through.
+trait SettlingDelay {
+    fn wait_us(&mut self, us: u32);
+}
+
+#[cfg(feature = "eh0")]
+impl<T: DelayUs<u32>> SettlingDelay for T {
+    fn wait_us(&mut self, us: u32) {
+        self.delay_us(us)
+    }
+}
+
+#[cfg(not(feature = "eh0"))]
+impl<T: DelayNs> SettlingDelay for T {
+    fn wait_us(&mut self, us: u32) {
+        DelayNs::delay_us(self, us)
+    }
+}
+
+/// Error produced by [`DigitalScanner`]
+#[derive(Debug)]
+pub enum DigitalScanError<P: OutputPin, E: OutputPin, SigError> {
+    /// Error selecting a channel
+    Select(Error<P, E>),
+    /// Error reading the common `SIG` line
+    Sig(SigError),
+}
+
+/// Wraps an enabled [`Cd74hc4067`] together with the common `SIG` pin wired
+/// as an [`InputPin`] and a blocking delay, turning the CD74HC4067's
+/// bidirectional switch into a 16-channel digital input scanner (e.g.
+/// polling 16 buttons over 5 GPIOs), rather than only ever driving the mux
+/// outward. Kept separate from the plain [`Cd74hc4067`] so output-only
+/// users aren't required to wire up or even have a `SIG` input pin.
+pub struct DigitalScanner<P, E, Sig, Delay> {
+    mux: Cd74hc4067<P, E, EnabledState>,
+    sig: Sig,
+    delay: Delay,
+    settling_us: u32,
+}
+
+impl<P, E, Sig, Delay> DigitalScanner<P, E, Sig, Delay>
+where
+    P: OutputPin,
+    E: OutputPin,
+    Sig: InputPin,
+    Delay: SettlingDelay,
+{
+    /// Wrap an already-enabled mux together with the common `SIG` pin, and
+    /// the delay used to wait out [`Self::settling_us`] between selecting
+    /// a channel and reading it.
+    pub fn new(mux: Cd74hc4067<P, E, EnabledState>, sig: Sig, delay: Delay) -> Self {
+        Self {
+            mux,
+            sig,
+            delay,
+            settling_us: DEFAULT_SETTLING_US,
+        }
+    }
+
+    /// Set the settling time awaited between selecting a channel and
+    /// reading it, overriding [`DEFAULT_SETTLING_US`].
+    pub fn with_settling_us(mut self, settling_us: u32) -> Self {
+        self.settling_us = settling_us;
+        self
+    }
+
+    /// Release the wrapped mux, `SIG` pin and delay.
+    pub fn release(self) -> (Cd74hc4067<P, E, EnabledState>, Sig, Delay) {
+        (self.mux, self.sig, self.delay)
+    }
+
+    /// Select channel `n`, wait out [`Self::settling_us`], then return the
+    /// logic level present on it.
+    ///
+    /// # Panics
+    ///
+    /// If `n` is out of range, then this function will panic.
+    pub fn read_channel_digital(
+        &mut self,
+        n: u8,
+    ) -> Result<bool, DigitalScanError<P, E, Sig::Error>> {
+        self.mux
+            .set_channel_active(n)
+            .map_err(DigitalScanError::Select)?;
+        self.delay.wait_us(self.settling_us);
+        self.sig.is_high().map_err(DigitalScanError::Sig)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -260,6 +769,8 @@ mod tests {
             pin_2,
             pin_3,
             pin_enable,
+            #[cfg(feature = "async")]
+            settling_ns: DEFAULT_SETTLING_NS,
             state: PhantomData::<DisabledState>,
         };
 
@@ -289,6 +800,8 @@ mod tests {
             pin_2,
             pin_3,
             pin_enable,
+            #[cfg(feature = "async")]
+            settling_ns: DEFAULT_SETTLING_NS,
             state: PhantomData::<DisabledState>,
         };
 
@@ -318,6 +831,8 @@ mod tests {
             pin_2,
             pin_3,
             pin_enable,
+            #[cfg(feature = "async")]
+            settling_ns: DEFAULT_SETTLING_NS,
             state: PhantomData::<DisabledState>,
         };
 
@@ -348,6 +863,8 @@ mod tests {
             pin_2,
             pin_3,
             pin_enable,
+            #[cfg(feature = "async")]
+            settling_ns: DEFAULT_SETTLING_NS,
             state: PhantomData::<DisabledState>,
         };
 
@@ -370,9 +887,331 @@ mod tests {
             pin_2,
             pin_3,
             pin_enable,
+            #[cfg(feature = "async")]
+            settling_ns: DEFAULT_SETTLING_NS,
             state: PhantomData::<DisabledState>,
         };
 
         let _unreachable_result = mux.set_channel_active(20);
     }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn set_channel_9_async() {
+        let pin_0 = PinMock::new(&[PinTransaction::set(PinState::High)]);
+        let pin_1 = PinMock::new(&[PinTransaction::set(PinState::Low)]);
+        let pin_2 = PinMock::new(&[PinTransaction::set(PinState::Low)]);
+        let pin_3 = PinMock::new(&[PinTransaction::set(PinState::High)]);
+
+        let pin_enable = PinMock::new(&[]);
+
+        let mut mux = Cd74hc4067 {
+            pin_0,
+            pin_1,
+            pin_2,
+            pin_3,
+            pin_enable,
+            settling_ns: 42,
+            state: PhantomData::<DisabledState>,
+        };
+
+        let mut delay = embedded_hal_mock::eh1::delay::NoopDelay::new();
+        futures::executor::block_on(mux.set_channel_active_async(9, &mut delay)).unwrap();
+
+        let (mut pin_0, mut pin_1, mut pin_2, mut pin_3, mut pin_enable) = mux.release();
+
+        pin_0.done();
+        pin_1.done();
+        pin_2.done();
+        pin_3.done();
+        pin_enable.done();
+    }
+
+    #[cfg(feature = "eh0")]
+    #[test]
+    fn scanning_mux_reads_selected_channel() {
+        use embedded_hal_mock::eh0::adc::{
+            Mock as AdcMock, MockChan1, Transaction as AdcTransaction,
+        };
+
+        let pin_0 = PinMock::new(&[PinTransaction::set(PinState::High)]);
+        let pin_1 = PinMock::new(&[PinTransaction::set(PinState::Low)]);
+        let pin_2 = PinMock::new(&[PinTransaction::set(PinState::Low)]);
+        let pin_3 = PinMock::new(&[PinTransaction::set(PinState::Low)]);
+        let pin_enable = PinMock::new(&[]);
+
+        let mux = Cd74hc4067 {
+            pin_0,
+            pin_1,
+            pin_2,
+            pin_3,
+            pin_enable,
+            #[cfg(feature = "async")]
+            settling_ns: DEFAULT_SETTLING_NS,
+            state: PhantomData::<EnabledState>,
+        };
+
+        let adc = AdcMock::new(&[AdcTransaction::read(1, 42)]);
+        let delay = embedded_hal_mock::eh0::delay::MockNoop::new();
+        let mut scanning_mux = ScanningMux::new(mux, adc, MockChan1, delay);
+
+        assert_eq!(scanning_mux.read_channel(1).unwrap(), 42);
+
+        let (mut mux, mut adc, _channel, _delay) = scanning_mux.release();
+
+        adc.done();
+        mux.pin_0.done();
+        mux.pin_1.done();
+        mux.pin_2.done();
+        mux.pin_3.done();
+        mux.pin_enable.done();
+    }
+
+    #[cfg(not(feature = "eh0"))]
+    #[derive(Default)]
+    struct InfallibleTrackingPin {
+        calls: std::vec::Vec<bool>,
+    }
+
+    #[cfg(not(feature = "eh0"))]
+    impl embedded_hal::digital::ErrorType for InfallibleTrackingPin {
+        type Error = Infallible;
+    }
+
+    #[cfg(not(feature = "eh0"))]
+    impl OutputPin for InfallibleTrackingPin {
+        fn set_low(&mut self) -> Result<(), Infallible> {
+            self.calls.push(false);
+            Ok(())
+        }
+
+        fn set_high(&mut self) -> Result<(), Infallible> {
+            self.calls.push(true);
+            Ok(())
+        }
+    }
+
+    #[cfg(not(feature = "eh0"))]
+    #[test]
+    fn set_channel_active_infallible_does_not_need_unwrapping() {
+        let mut mux = Cd74hc4067 {
+            pin_0: InfallibleTrackingPin::default(),
+            pin_1: InfallibleTrackingPin::default(),
+            pin_2: InfallibleTrackingPin::default(),
+            pin_3: InfallibleTrackingPin::default(),
+            pin_enable: InfallibleTrackingPin::default(),
+            #[cfg(feature = "async")]
+            settling_ns: DEFAULT_SETTLING_NS,
+            state: PhantomData::<DisabledState>,
+        };
+
+        mux.set_channel_active_infallible(9);
+
+        let (pin_0, pin_1, pin_2, pin_3, _pin_enable) = mux.release();
+        assert_eq!(pin_0.calls, std::vec![true]);
+        assert_eq!(pin_1.calls, std::vec![false]);
+        assert_eq!(pin_2.calls, std::vec![false]);
+        assert_eq!(pin_3.calls, std::vec![true]);
+    }
+
+    #[test]
+    fn cascade_enables_only_the_selected_leaf() {
+        let root = Cd74hc4067 {
+            pin_0: PinMock::new(&[PinTransaction::set(PinState::High)]),
+            pin_1: PinMock::new(&[PinTransaction::set(PinState::Low)]),
+            pin_2: PinMock::new(&[PinTransaction::set(PinState::Low)]),
+            pin_3: PinMock::new(&[PinTransaction::set(PinState::Low)]),
+            pin_enable: PinMock::new(&[]),
+            #[cfg(feature = "async")]
+            settling_ns: DEFAULT_SETTLING_NS,
+            state: PhantomData::<EnabledState>,
+        };
+
+        let leaf_0 = Cd74hc4067 {
+            pin_0: PinMock::new(&[]),
+            pin_1: PinMock::new(&[]),
+            pin_2: PinMock::new(&[]),
+            pin_3: PinMock::new(&[]),
+            // Leaf 0 is never the active leaf in this test, so it is left
+            // disabled throughout and its `pin_enable` is never touched.
+            pin_enable: PinMock::new(&[]),
+            #[cfg(feature = "async")]
+            settling_ns: DEFAULT_SETTLING_NS,
+            state: PhantomData::<DisabledState>,
+        };
+
+        let leaf_1 = Cd74hc4067 {
+            pin_0: PinMock::new(&[PinTransaction::set(PinState::High)]),
+            pin_1: PinMock::new(&[PinTransaction::set(PinState::Low)]),
+            pin_2: PinMock::new(&[PinTransaction::set(PinState::Low)]),
+            pin_3: PinMock::new(&[PinTransaction::set(PinState::Low)]),
+            // Enabled while active, then disabled again on `release`.
+            pin_enable: PinMock::new(&[
+                PinTransaction::set(PinState::Low),
+                PinTransaction::set(PinState::High),
+            ]),
+            #[cfg(feature = "async")]
+            settling_ns: DEFAULT_SETTLING_NS,
+            state: PhantomData::<DisabledState>,
+        };
+
+        let mut cascade = Cascade::new(root, [leaf_0, leaf_1]);
+
+        // Global channel 17 is leaf 1, channel 1.
+        cascade.set_channel_active(17).unwrap();
+
+        let (mut root, [leaf_0, leaf_1]) = cascade.release().unwrap();
+
+        root.pin_0.done();
+        root.pin_1.done();
+        root.pin_2.done();
+        root.pin_3.done();
+        for leaf in [leaf_0, leaf_1] {
+            let (mut pin_0, mut pin_1, mut pin_2, mut pin_3, mut pin_enable) = leaf.release();
+            pin_0.done();
+            pin_1.done();
+            pin_2.done();
+            pin_3.done();
+            pin_enable.done();
+        }
+    }
+
+    #[cfg(not(feature = "eh0"))]
+    #[derive(Debug)]
+    struct FlakyPinError;
+
+    #[cfg(not(feature = "eh0"))]
+    impl embedded_hal::digital::Error for FlakyPinError {
+        fn kind(&self) -> embedded_hal::digital::ErrorKind {
+            embedded_hal::digital::ErrorKind::Other
+        }
+    }
+
+    /// An output pin whose next `set_low`/`set_high` call fails exactly
+    /// once, then succeeds from then on -- used to simulate a transient
+    /// failure of a [`Cascade`] leaf's `pin_enable`.
+    #[cfg(not(feature = "eh0"))]
+    #[derive(Default)]
+    struct FlakyPin {
+        fail_next: bool,
+    }
+
+    #[cfg(not(feature = "eh0"))]
+    impl embedded_hal::digital::ErrorType for FlakyPin {
+        type Error = FlakyPinError;
+    }
+
+    #[cfg(not(feature = "eh0"))]
+    impl OutputPin for FlakyPin {
+        fn set_low(&mut self) -> Result<(), FlakyPinError> {
+            if core::mem::take(&mut self.fail_next) {
+                Err(FlakyPinError)
+            } else {
+                Ok(())
+            }
+        }
+
+        fn set_high(&mut self) -> Result<(), FlakyPinError> {
+            if core::mem::take(&mut self.fail_next) {
+                Err(FlakyPinError)
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[cfg(not(feature = "eh0"))]
+    #[test]
+    fn cascade_survives_a_failed_leaf_transition_without_panicking() {
+        let root = Cd74hc4067 {
+            pin_0: InfallibleTrackingPin::default(),
+            pin_1: InfallibleTrackingPin::default(),
+            pin_2: InfallibleTrackingPin::default(),
+            pin_3: InfallibleTrackingPin::default(),
+            pin_enable: FlakyPin::default(),
+            #[cfg(feature = "async")]
+            settling_ns: DEFAULT_SETTLING_NS,
+            state: PhantomData::<EnabledState>,
+        };
+
+        let leaf_0 = Cd74hc4067 {
+            pin_0: InfallibleTrackingPin::default(),
+            pin_1: InfallibleTrackingPin::default(),
+            pin_2: InfallibleTrackingPin::default(),
+            pin_3: InfallibleTrackingPin::default(),
+            pin_enable: FlakyPin::default(),
+            #[cfg(feature = "async")]
+            settling_ns: DEFAULT_SETTLING_NS,
+            state: PhantomData::<DisabledState>,
+        };
+
+        // Its enable pin fails the first time it's toggled, then works.
+        let leaf_1 = Cd74hc4067 {
+            pin_0: InfallibleTrackingPin::default(),
+            pin_1: InfallibleTrackingPin::default(),
+            pin_2: InfallibleTrackingPin::default(),
+            pin_3: InfallibleTrackingPin::default(),
+            pin_enable: FlakyPin { fail_next: true },
+            #[cfg(feature = "async")]
+            settling_ns: DEFAULT_SETTLING_NS,
+            state: PhantomData::<DisabledState>,
+        };
+
+        let mut cascade = Cascade::new(root, [leaf_0, leaf_1]);
+
+        // Global channel 17 is leaf 1, channel 1. Activating it fails
+        // because leaf 1's enable pin is flaky.
+        match cascade.set_channel_active(17) {
+            Ok(()) => panic!("expected the flaky enable pin to fail"),
+            Err(_) => {}
+        }
+
+        // The failed leaf must not be lost: a second attempt at the same
+        // valid index must not panic, and now succeeds since the pin only
+        // fails once.
+        match cascade.set_channel_active(17) {
+            Ok(()) => {}
+            Err(_) => panic!("leaf must still be usable after a failed transition"),
+        }
+    }
+
+    #[test]
+    fn digital_scanner_reads_selected_channel() {
+        let pin_0 = PinMock::new(&[PinTransaction::set(PinState::High)]);
+        let pin_1 = PinMock::new(&[PinTransaction::set(PinState::Low)]);
+        let pin_2 = PinMock::new(&[PinTransaction::set(PinState::Low)]);
+        let pin_3 = PinMock::new(&[PinTransaction::set(PinState::Low)]);
+        let pin_enable = PinMock::new(&[]);
+
+        let mux = Cd74hc4067 {
+            pin_0,
+            pin_1,
+            pin_2,
+            pin_3,
+            pin_enable,
+            #[cfg(feature = "async")]
+            settling_ns: DEFAULT_SETTLING_NS,
+            state: PhantomData::<EnabledState>,
+        };
+
+        let sig = PinMock::new(&[PinTransaction::get(PinState::High)]);
+
+        #[cfg(feature = "eh0")]
+        let delay = embedded_hal_mock::eh0::delay::MockNoop::new();
+        #[cfg(not(feature = "eh0"))]
+        let delay = embedded_hal_mock::eh1::delay::NoopDelay::new();
+
+        let mut scanner = DigitalScanner::new(mux, sig, delay);
+
+        assert!(scanner.read_channel_digital(1).unwrap());
+
+        let (mut mux, mut sig, _delay) = scanner.release();
+
+        sig.done();
+        mux.pin_0.done();
+        mux.pin_1.done();
+        mux.pin_2.done();
+        mux.pin_3.done();
+        mux.pin_enable.done();
+    }
 }
@@ -59,20 +59,10 @@ fn main() -> ! {
             Pin<Output<PushPull>>,
             DisabledState,
         >| {
-            match hc.set_channel_active(pin as u8) {
-                Ok(_) => {}
-                Err(_) => {}
-            }
-            let enabled = match hc.enable() {
-                Ok(d) => d,
-                Err(_) => loop {},
-            };
+            hc.set_channel_active_infallible(pin as u8);
+            let enabled = hc.enable_infallible();
             delay.delay_ms(duration);
-            let disabled = match enabled.disable() {
-                Ok(d) => d,
-                Err(_) => loop {},
-            };
-            disabled
+            enabled.disable_infallible()
         };
 
         let mut disabled = match cd74hc4067::Cd74hc4067::new(pin_0, pin_1, pin_2, pin_3, pin_enable)